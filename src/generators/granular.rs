@@ -0,0 +1,147 @@
+//! # Granular
+
+use super::*;
+use std::time::Duration;
+
+/// A single active grain: a short window into [`Granular`]'s buffer with
+/// its own read position and remaining lifetime.
+///
+/// [`Granular`]: struct.Granular.html
+struct Grain {
+	position: MathT,
+	increment: MathT,
+	length: usize,
+	remaining: usize,
+}
+
+impl Grain {
+	/// Raised-cosine (Hann) envelope value for the grain's current point in
+	/// its lifetime, so it fades in and out without clicking.
+	fn envelope(&self) -> MathT {
+		let t = (self.length - self.remaining) as MathT / self.length as MathT;
+
+		0.5 - 0.5 * (2.0 * std::f64::consts::PI * t).cos()
+	}
+}
+
+/// Granular synthesis generator. Plays a loaded buffer back as a cloud of
+/// short, overlapping, windowed grains rather than as a single continuous
+/// stream, allowing texture and time-stretch effects the crate's simple
+/// periodic oscillators can't produce.
+pub struct Granular {
+	buffer: Vec<StereoData>,
+	grain_len: usize,
+	density: MathT,
+	jitter: MathT,
+	pitch: MathT,
+	position: MathT,
+	speed: MathT,
+	spawn_in: MathT,
+	rng: u64,
+	grains: Vec<Grain>,
+}
+
+impl Granular {
+	/// Constructs a new Granular generator reading from `buffer`.
+	///
+	/// # Parameters
+	///
+	/// * `buffer` - The sample buffer grains are read from.
+	/// * `grain_duration` - The length of each individual grain.
+	/// * `density` - How many grains to spawn per second. Higher values
+	/// give denser, more overlapped playback.
+	pub fn new(buffer: Vec<StereoData>, grain_duration: Duration, density: MathT) -> Self {
+		Granular {
+			buffer,
+			grain_len: (grain_duration.as_secs_f64() * SAMPLE_RATE as MathT).max(1.0) as usize,
+			density: density.max(0.001),
+			jitter: 0.0,
+			pitch: 1.0,
+			position: 0.0,
+			speed: 1.0,
+			spawn_in: 0.0,
+			rng: 0x9E3779B97F4A7C15,
+			grains: Vec::new(),
+		}
+	}
+
+	/// Sets the playback position jitter, in samples. Each spawned grain
+	/// starts reading at the current playback position plus a random
+	/// offset in `[-jitter, jitter]`.
+	pub fn set_jitter(&mut self, jitter: MathT) {
+		self.jitter = jitter.max(0.0);
+	}
+
+	/// Sets the per-grain pitch as a playback-rate multiplier (`1.0` is
+	/// unchanged, `2.0` is an octave up).
+	pub fn set_pitch(&mut self, pitch: MathT) {
+		self.pitch = pitch;
+	}
+
+	/// Sets how fast the playback position advances through the buffer, in
+	/// buffer-samples per output sample.
+	pub fn set_speed(&mut self, speed: MathT) {
+		self.speed = speed;
+	}
+
+	/// Sets the grain spawn density in grains per second.
+	pub fn set_density(&mut self, density: MathT) {
+		self.density = density.max(0.001);
+	}
+
+	/// Cheap xorshift64 step used for position jitter, so grain placement
+	/// doesn't need an external RNG dependency.
+	fn next_rand(&mut self) -> MathT {
+		self.rng ^= self.rng << 13;
+		self.rng ^= self.rng >> 7;
+		self.rng ^= self.rng << 17;
+
+		(self.rng >> 11) as MathT / (1u64 << 53) as MathT
+	}
+
+	fn spawn_grain(&mut self) {
+		if self.buffer.is_empty() {
+			return;
+		}
+
+		let offset = (self.next_rand() * 2.0 - 1.0) * self.jitter;
+
+		self.grains.push(Grain {
+			position: self.position + offset,
+			increment: self.pitch,
+			length: self.grain_len,
+			remaining: self.grain_len,
+		});
+	}
+}
+
+impl Generator for Granular {
+	fn process(&mut self) -> StereoData {
+		if self.buffer.is_empty() {
+			return StereoData::default();
+		}
+
+		self.spawn_in -= 1.0;
+		if self.spawn_in <= 0.0 {
+			self.spawn_grain();
+			self.spawn_in += SAMPLE_RATE as MathT / self.density;
+		}
+
+		let mut out = StereoData::default();
+
+		for grain in &mut self.grains {
+			out += self.buffer[
+				(grain.position.rem_euclid(self.buffer.len() as MathT)) as usize
+			] * (grain.envelope() as SampleT);
+
+			grain.position += grain.increment;
+			grain.remaining -= 1;
+		}
+
+		self.grains.retain(|g| g.remaining > 0);
+
+		self.position = (self.position + self.speed).rem_euclid(self.buffer.len() as MathT);
+
+		out
+	}
+}
@@ -29,6 +29,7 @@ pub trait Generator {
     fn process(&mut self) -> StereoData;
 }
 
+pub mod granular;
 pub mod noise;
 pub mod sawtooth;
 pub mod sine;
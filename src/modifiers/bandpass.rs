@@ -1,10 +1,11 @@
 //! # Band Pass
 
 use super::*;
+use super::smoothed::{Smoothed, DEFAULT_RAMP};
 
 pub struct BandPass {
-	central_f: MathT,
-	quality: MathT,
+	central_f: Smoothed<MathT>,
+	quality: Smoothed<MathT>,
 	a0: MathT,
 	b1: MathT,
 	b2: MathT,
@@ -17,8 +18,8 @@ pub struct BandPass {
 impl BandPass {
 	pub fn new(f: MathT, q: MathT) -> Self {
 		let mut bp = BandPass {
-			central_f: f,
-			quality: q,
+			central_f: Smoothed::new(f),
+			quality: Smoothed::new(q),
 			a0: 0.0,
 			b1: 0.0,
 			b2: 0.0,
@@ -34,9 +35,12 @@ impl BandPass {
 	}
 
 	pub fn from_corners(f: (MathT,MathT)) -> Self {
+		let central_f = (f.0*f.1).abs().sqrt();
+		let quality = central_f/(f.1-f.0).abs();
+
 		let mut bp = BandPass {
-			central_f: (f.0*f.1).abs().sqrt(),
-			quality: (f.0*f.1).abs().sqrt()/(f.1-f.0).abs(),
+			central_f: Smoothed::new(central_f),
+			quality: Smoothed::new(quality),
 			a0: 0.0,
 			b1: 0.0,
 			b2: 0.0,
@@ -52,29 +56,32 @@ impl BandPass {
 	}
 
 	pub fn get_central_frequency(&self) -> MathT {
-		self.central_f
+		self.central_f.current()
 	}
 
+	/// Glides the central frequency to `f` over [`DEFAULT_RAMP`] instead of
+	/// jumping instantly, to avoid zipper noise when automated.
 	pub fn set_central_frequency(&mut self, f: MathT) {
-		self.central_f = f;
-
-		self.reset();
+		self.central_f.set_target(f, DEFAULT_RAMP);
 	}
 
 	pub fn get_quality(&self) -> MathT {
-		self.quality
+		self.quality.current()
 	}
 
+	/// Glides the quality to `q` over [`DEFAULT_RAMP`] instead of jumping
+	/// instantly, to avoid zipper noise when automated.
 	pub fn set_quality(&mut self, q: MathT) {
-		self.quality = q;
-
-		self.reset();
+		self.quality.set_target(q, DEFAULT_RAMP);
 	}
 
 	pub fn get_corner_frequencies(&self) -> (MathT,MathT) {
+		let central_f = self.central_f.current();
+		let quality = self.quality.current();
+
 		let a = 1.0;
-		let b = -self.central_f/self.quality;
-		let c = -self.central_f*self.central_f;
+		let b = -central_f/quality;
+		let c = -central_f*central_f;
 
 		let (p,n) = quadratic(a,b,c);
 		let fl = if p > 0.0 {
@@ -88,16 +95,20 @@ impl BandPass {
 	}
 
 	pub fn set_corner_frequencies(&mut self, f: (MathT,MathT)) {
-		self.central_f = (f.0 * f.1).sqrt();
-		self.quality = self.central_f/(f.0-f.1).abs();
+		let central_f = (f.0 * f.1).sqrt();
+		let quality = central_f/(f.0-f.1).abs();
 
-		self.reset();
+		self.central_f.set_target(central_f, DEFAULT_RAMP);
+		self.quality.set_target(quality, DEFAULT_RAMP);
 	}
 
 	fn reset(&mut self) {
+		let central_f = self.central_f.current();
+		let quality = self.quality.current();
+
 		let a = 1.0;
-		let b = -self.central_f/self.quality;
-		let c = -self.central_f*self.central_f;
+		let b = -central_f/quality;
+		let c = -central_f*central_f;
 
 		let (p,n) = quadratic(a,b,c);
 		let fl = if p > 0.0 {
@@ -124,12 +135,20 @@ impl BandPass {
 
 impl Modifier for BandPass {
 	fn process(&mut self, x: StereoData) -> StereoData {
+		let was_ramping = self.central_f.is_ramping() || self.quality.is_ramping();
+		self.central_f.tick();
+		self.quality.tick();
+
+		if was_ramping {
+			self.reset();
+		}
+
 		let y = StereoData::from_stereo(
 			(self.a0 * (x.left() - self.x2.left()) as MathT +
-			self.b1 * self.y1.left() as MathT - 
+			self.b1 * self.y1.left() as MathT -
 			self.b2 * self.y2.left() as MathT) as SampleT,
 			(self.a0 * (x.right() - self.x2.right()) as MathT +
-			self.b1 * self.y1.right() as MathT - 
+			self.b1 * self.y1.right() as MathT -
 			self.b2 * self.y2.right() as MathT) as SampleT
 		);
 
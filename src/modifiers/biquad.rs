@@ -0,0 +1,232 @@
+//! # Biquad
+//!
+//! A single reusable second-order filter with a runtime-selectable
+//! response, built from the standard RBJ bilinear-transform coefficient
+//! formulas.
+
+use super::*;
+
+/// The response a [`Biquad`] computes its coefficients for.
+///
+/// [`Biquad`]: struct.Biquad.html
+#[derive(Copy, Clone, PartialEq)]
+pub enum FilterMode {
+	LowPass,
+	HighPass,
+	BandPass,
+	Notch,
+	Peaking,
+	LowShelf,
+	HighShelf,
+}
+
+/// General-purpose second-order (biquad) filter offering every common
+/// response behind one reusable type, switchable at runtime via
+/// [`Biquad::set_mode`].
+///
+/// [`Biquad::set_mode`]: struct.Biquad.html#method.set_mode
+pub struct Biquad {
+	mode: FilterMode,
+
+	b0: SampleT,
+	b1: SampleT,
+	b2: SampleT,
+	a1: SampleT,
+	a2: SampleT,
+
+	x1: SampleT,
+	x2: SampleT,
+	y1: SampleT,
+	y2: SampleT,
+
+	fc: MathT,
+	q: MathT,
+	gain_db: MathT,
+}
+
+impl Biquad {
+	/// Creates a new Biquad for the given mode, cutoff/central frequency,
+	/// and resonance (Q).
+	///
+	/// # Parameters
+	///
+	/// * `mode` - The filter response to compute.
+	/// * `fc` - The cutoff (or central) frequency.
+	/// * `q` - The resonance/quality factor of the filter.
+	pub fn new(mode: FilterMode, fc: MathT, q: MathT) -> Biquad {
+		let mut bq = Biquad {
+			mode,
+			b0: SampleT::default(),
+			b1: SampleT::default(),
+			b2: SampleT::default(),
+			a1: SampleT::default(),
+			a2: SampleT::default(),
+			x1: SampleT::default(),
+			x2: SampleT::default(),
+			y1: SampleT::default(),
+			y2: SampleT::default(),
+			fc,
+			q,
+			gain_db: 0.0,
+		};
+
+		bq.reset();
+
+		bq
+	}
+
+	/// Returns the filter's current mode.
+	pub fn get_mode(&self) -> FilterMode {
+		self.mode
+	}
+
+	/// Switches the filter to a new mode, recomputing coefficients.
+	pub fn set_mode(&mut self, mode: FilterMode) {
+		self.mode = mode;
+		self.reset();
+	}
+
+	/// Returns the central frequency of the filter.
+	pub fn get_central_frequency(&self) -> MathT {
+		self.fc
+	}
+
+	/// Sets the central frequency of the filter.
+	pub fn set_central_frequency(&mut self, fc: MathT) {
+		self.fc = fc;
+		self.reset();
+	}
+
+	/// Returns the resonance (Q) of the filter.
+	pub fn get_resonance(&self) -> MathT {
+		self.q
+	}
+
+	/// Sets the resonance (Q) of the filter.
+	pub fn set_resonance(&mut self, q: MathT) {
+		self.q = q;
+		self.reset();
+	}
+
+	/// Sets the gain in decibels used by the peaking/shelving modes.
+	pub fn set_gain_db(&mut self, gain_db: MathT) {
+		self.gain_db = gain_db;
+		self.reset();
+	}
+
+	fn reset(&mut self) {
+		let w0 = 2.0 * std::f64::consts::PI * self.fc * INV_SAMPLE_RATE;
+		let cos0 = w0.cos();
+		let sin0 = w0.sin();
+		let alpha = sin0 / (2.0 * self.q);
+		let a = (10.0_f64).powf(self.gain_db / 40.0);
+
+		let (b0, b1, b2, a0, a1, a2) = match self.mode {
+			FilterMode::LowPass => (
+				(1.0 - cos0) / 2.0,
+				1.0 - cos0,
+				(1.0 - cos0) / 2.0,
+				1.0 + alpha,
+				-2.0 * cos0,
+				1.0 - alpha,
+			),
+			FilterMode::HighPass => (
+				(1.0 + cos0) / 2.0,
+				-(1.0 + cos0),
+				(1.0 + cos0) / 2.0,
+				1.0 + alpha,
+				-2.0 * cos0,
+				1.0 - alpha,
+			),
+			FilterMode::BandPass => (
+				alpha,
+				0.0,
+				-alpha,
+				1.0 + alpha,
+				-2.0 * cos0,
+				1.0 - alpha,
+			),
+			FilterMode::Notch => (
+				1.0,
+				-2.0 * cos0,
+				1.0,
+				1.0 + alpha,
+				-2.0 * cos0,
+				1.0 - alpha,
+			),
+			FilterMode::Peaking => (
+				1.0 + alpha * a,
+				-2.0 * cos0,
+				1.0 - alpha * a,
+				1.0 + alpha / a,
+				-2.0 * cos0,
+				1.0 - alpha / a,
+			),
+			FilterMode::LowShelf => {
+				let sq = 2.0 * a.sqrt() * alpha;
+				(
+					a * ((a + 1.0) - (a - 1.0) * cos0 + sq),
+					2.0 * a * ((a - 1.0) - (a + 1.0) * cos0),
+					a * ((a + 1.0) - (a - 1.0) * cos0 - sq),
+					(a + 1.0) + (a - 1.0) * cos0 + sq,
+					-2.0 * ((a - 1.0) + (a + 1.0) * cos0),
+					(a + 1.0) + (a - 1.0) * cos0 - sq,
+				)
+			},
+			FilterMode::HighShelf => {
+				let sq = 2.0 * a.sqrt() * alpha;
+				(
+					a * ((a + 1.0) + (a - 1.0) * cos0 + sq),
+					-2.0 * a * ((a - 1.0) + (a + 1.0) * cos0),
+					a * ((a + 1.0) + (a - 1.0) * cos0 - sq),
+					(a + 1.0) - (a - 1.0) * cos0 + sq,
+					2.0 * ((a - 1.0) - (a + 1.0) * cos0),
+					(a + 1.0) - (a - 1.0) * cos0 - sq,
+				)
+			},
+		};
+
+		self.b0 = (b0 / a0) as SampleT;
+		self.b1 = (b1 / a0) as SampleT;
+		self.b2 = (b2 / a0) as SampleT;
+		self.a1 = (a1 / a0) as SampleT;
+		self.a2 = (a2 / a0) as SampleT;
+	}
+}
+
+impl Modifier<SampleT> for Biquad {
+	fn process(&mut self, x: SampleT) -> SampleT {
+		let y = self.b0 * x + self.b1 * self.x1 + self.b2 * self.x2
+			- self.a1 * self.y1 - self.a2 * self.y2;
+
+		self.x2 = self.x1;
+		self.x1 = x;
+		self.y2 = self.y1;
+		self.y1 = y;
+
+		y
+	}
+
+	fn process_block(&mut self, input: &[SampleT], output: &mut [SampleT]) {
+		let (b0, b1, b2, a1, a2) = (self.b0, self.b1, self.b2, self.a1, self.a2);
+		let (mut x1, mut x2) = (self.x1, self.x2);
+		let (mut y1, mut y2) = (self.y1, self.y2);
+
+		for (x, y) in input.iter().zip(output.iter_mut()) {
+			let x = *x;
+			let out = b0*x + b1*x1 + b2*x2 - a1*y1 - a2*y2;
+
+			x2 = x1;
+			x1 = x;
+			y2 = y1;
+			y1 = out;
+
+			*y = out;
+		}
+
+		self.x1 = x1;
+		self.x2 = x2;
+		self.y1 = y1;
+		self.y2 = y2;
+	}
+}
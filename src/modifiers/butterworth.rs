@@ -0,0 +1,157 @@
+//! # Butterworth
+//!
+//! Arbitrary-order cascaded Butterworth high/low-pass filters, built from
+//! [`Biquad`] stages (plus a one-pole stage, run ahead of them, for odd
+//! orders) instead of being hard-wired to a single response.
+//!
+//! [`Biquad`]: struct.Biquad.html
+
+use super::*;
+use super::biquad::{Biquad, FilterMode};
+
+/// Single first-order stage used to realize the leftover pole of an
+/// odd-order Butterworth cascade.
+struct OnePole {
+	lowpass: bool,
+	a0: SampleT,
+	b1: SampleT,
+	x1: SampleT,
+	y1: SampleT,
+}
+
+impl OnePole {
+	fn new(fc: MathT, lowpass: bool) -> Self {
+		let theta = (std::f64::consts::PI * fc * INV_SAMPLE_RATE).tan();
+
+		let (a0, b1) = if lowpass {
+			(theta / (1.0 + theta), (1.0 - theta) / (1.0 + theta))
+		} else {
+			(1.0 / (1.0 + theta), (1.0 - theta) / (1.0 + theta))
+		};
+
+		OnePole {
+			lowpass,
+			a0: a0 as SampleT,
+			b1: b1 as SampleT,
+			x1: SampleT::default(),
+			y1: SampleT::default(),
+		}
+	}
+
+	fn process(&mut self, x: SampleT) -> SampleT {
+		let y = if self.lowpass {
+			self.a0 * (x + self.x1) + self.b1 * self.y1
+		} else {
+			self.a0 * (x - self.x1) + self.b1 * self.y1
+		};
+
+		self.x1 = x;
+		self.y1 = y;
+
+		y
+	}
+}
+
+/// Returns the Q of the `k`th second-order stage (`k` in `0..N/2`) of an
+/// order-`N` Butterworth cascade, derived from the analog pole angle
+/// `theta_k = PI*(2k+N+1)/(2N)` (the same prototype used by [`ZpkDesign`]):
+/// `Q = -1/(2*cos(theta_k))`. Using `2k+1` instead of `2k+N+1` only agrees
+/// with this for even `N`; for odd `N` the real pole shifts the pair
+/// angles and that formula undershoots Q.
+///
+/// [`ZpkDesign`]: ../zpk/struct.ZpkDesign.html
+fn stage_q(k: usize, order: usize) -> MathT {
+	let theta = std::f64::consts::PI * (2 * k + order + 1) as MathT / (2.0 * order as MathT);
+
+	-1.0 / (2.0 * theta.cos())
+}
+
+/// Cascaded Butterworth low-pass filter of arbitrary order `N`, giving
+/// 6*N dB/octave roll-off from one reusable type instead of being stuck at
+/// [`HighPass`]'s fixed 18 dB/octave.
+///
+/// [`HighPass`]: struct.HighPass.html
+pub struct ButterworthLowPass {
+	stages: Vec<Biquad>,
+	one_pole: Option<OnePole>,
+}
+
+impl ButterworthLowPass {
+	/// Creates a new ButterworthLowPass of the given `order` and cutoff
+	/// frequency `fc`.
+	pub fn new(fc: MathT, order: usize) -> Self {
+		let order = order.max(1);
+		let pairs = order / 2;
+
+		let stages = (0..pairs)
+			.map(|k| Biquad::new(FilterMode::LowPass, fc, stage_q(k, order)))
+			.collect();
+
+		let one_pole = if order % 2 == 1 {
+			Some(OnePole::new(fc, true))
+		} else {
+			None
+		};
+
+		ButterworthLowPass { stages, one_pole }
+	}
+}
+
+impl Modifier<SampleT> for ButterworthLowPass {
+	fn process(&mut self, x: SampleT) -> SampleT {
+		let mut y = x;
+
+		if let Some(pole) = &mut self.one_pole {
+			y = pole.process(y);
+		}
+
+		for stage in &mut self.stages {
+			y = stage.process(y);
+		}
+
+		y
+	}
+}
+
+/// Cascaded Butterworth high-pass filter of arbitrary order `N`.
+pub struct ButterworthHighPass {
+	stages: Vec<Biquad>,
+	one_pole: Option<OnePole>,
+}
+
+impl ButterworthHighPass {
+	/// Creates a new ButterworthHighPass of the given `order` and cutoff
+	/// frequency `fc`.
+	pub fn new(fc: MathT, order: usize) -> Self {
+		let order = order.max(1);
+		let pairs = order / 2;
+
+		let stages = (0..pairs)
+			.map(|k| Biquad::new(FilterMode::HighPass, fc, stage_q(k, order)))
+			.collect();
+
+		let one_pole = if order % 2 == 1 {
+			Some(OnePole::new(fc, false))
+		} else {
+			None
+		};
+
+		ButterworthHighPass { stages, one_pole }
+	}
+}
+
+impl Modifier<SampleT> for ButterworthHighPass {
+	fn process(&mut self, x: SampleT) -> SampleT {
+		let mut y = x;
+
+		if let Some(pole) = &mut self.one_pole {
+			y = pole.process(y);
+		}
+
+		for stage in &mut self.stages {
+			y = stage.process(y);
+		}
+
+		y
+	}
+}
@@ -0,0 +1,161 @@
+//! # Compressor
+
+use super::*;
+use std::time::Duration;
+
+/// Hierarchic (binary tree) reducer tracking the maximum absolute sample
+/// seen over a power-of-two lookahead window.
+///
+/// Leaves hold the per-sample amplitude (`max(abs(left),abs(right))`) and
+/// each internal node holds the max of its two children, so the window
+/// maximum is always available at the root. Pushing a new sample overwrites
+/// the oldest leaf and walks back up to the root recomputing only the
+/// ancestors of that leaf, making each update `O(log N)` instead of
+/// rescanning the whole window.
+struct PeakWindow {
+	tree: Vec<SampleT>,
+	size: usize,
+	pos: usize,
+}
+
+impl PeakWindow {
+	/// Creates a new peak window of at least `window` samples, rounded up to
+	/// the next power of two.
+	fn new(window: usize) -> Self {
+		let size = window.max(1).next_power_of_two();
+
+		PeakWindow {
+			tree: vec![SampleT::default(); 2 * size - 1],
+			size,
+			pos: 0,
+		}
+	}
+
+	/// Returns the window size the lookahead delay line must match for the
+	/// peak computed on a given sample to have been observed before that
+	/// sample reaches the output.
+	fn size(&self) -> usize {
+		self.size
+	}
+
+	/// Writes the next leaf and recomputes its ancestors, returning the new
+	/// window maximum.
+	fn push(&mut self, x: SampleT) -> SampleT {
+		let mut i = self.size - 1 + self.pos;
+		self.tree[i] = x;
+
+		while i > 0 {
+			let parent = (i - 1) / 2;
+			self.tree[parent] = self.tree[2 * parent + 1].max(self.tree[2 * parent + 2]);
+			i = parent;
+		}
+
+		self.pos = (self.pos + 1) % self.size;
+
+		self.tree[0]
+	}
+}
+
+/// Dynamics processor providing compression and limiting with a windowed
+/// peak detector, so transients are caught before they clip rather than
+/// after: the output is delayed by the lookahead window so the gain has
+/// already reacted to a peak by the time that peak reaches the output.
+pub struct Compressor {
+	threshold: MathT,
+	ratio: MathT,
+	attack: SampleT,
+	release: SampleT,
+	makeup: SampleT,
+	window: PeakWindow,
+	delay: Vec<StereoData>,
+	delay_pos: usize,
+	g: SampleT,
+}
+
+impl Compressor {
+	/// Constructs a new Compressor.
+	///
+	/// # Parameters
+	///
+	/// * `threshold` - Threshold in decibels above which gain reduction is
+	/// applied.
+	/// * `ratio` - Input/output ratio applied above the threshold. Values
+	/// at or above `20.0` behave as a hard limiter.
+	/// * `attack` - Time taken for the gain reduction to engage.
+	/// * `release` - Time taken for the gain reduction to release.
+	/// * `makeup` - Makeup gain in decibels applied to the output.
+	/// * `lookahead` - Size in samples of the peak-detection window. Rounded
+	/// up to the next power of two.
+	pub fn new(threshold: MathT, ratio: MathT, attack: Duration, release: Duration, makeup: MathT, lookahead: usize) -> Self {
+		let window = PeakWindow::new(lookahead);
+		let delay = vec![StereoData::default(); window.size()];
+
+		Compressor {
+			threshold,
+			ratio: ratio.max(1.0),
+			attack: (1.0 / (attack.as_secs_f64() * SAMPLE_RATE as MathT)) as SampleT,
+			release: (1.0 / (release.as_secs_f64() * SAMPLE_RATE as MathT)) as SampleT,
+			makeup: db_linear(makeup) as SampleT,
+			window,
+			delay,
+			delay_pos: 0,
+			g: 1.0,
+		}
+	}
+
+	/// Returns the threshold in decibels.
+	pub fn get_threshold(&self) -> MathT {
+		self.threshold
+	}
+
+	/// Sets the threshold in decibels.
+	pub fn set_threshold(&mut self, threshold: MathT) {
+		self.threshold = threshold;
+	}
+
+	/// Returns the compression ratio.
+	pub fn get_ratio(&self) -> MathT {
+		self.ratio
+	}
+
+	/// Sets the compression ratio. Clamped to be at least `1.0`.
+	pub fn set_ratio(&mut self, ratio: MathT) {
+		self.ratio = ratio.max(1.0);
+	}
+
+	/// Computes the target linear gain for a given window-max peak, applying
+	/// the static compression curve above `threshold`.
+	fn gain_computer(&self, peak: SampleT) -> SampleT {
+		let peak_db = linear_db(peak as MathT);
+		let over = peak_db - self.threshold;
+
+		if over > 0.0 {
+			db_linear(self.threshold + over / self.ratio - peak_db) as SampleT
+		} else {
+			1.0
+		}
+	}
+}
+
+impl Modifier for Compressor {
+	fn process(&mut self, x: StereoData) -> StereoData {
+		let peak = self.window.push(x.left().abs().max(x.right().abs()));
+		let target = self.gain_computer(peak);
+
+		if target < self.g {
+			self.g = (self.g - self.attack).max(target);
+		} else if target > self.g {
+			self.g = (self.g + self.release).min(target);
+		}
+
+		// The gain above already accounts for every sample up through `x`,
+		// including ones still `delay.len()` samples ahead of what's about
+		// to be emitted, so the sample leaving the delay line here has had
+		// its gain reduction applied in advance of reaching the output.
+		let delayed = self.delay[self.delay_pos];
+		self.delay[self.delay_pos] = x;
+		self.delay_pos = (self.delay_pos + 1) % self.delay.len();
+
+		delayed * self.g * self.makeup
+	}
+}
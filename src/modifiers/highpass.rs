@@ -1,9 +1,15 @@
 //! # HighPass
-//! 
+//!
 //! 18dB/octave
 //! Derived from 3rd Order Butterworth Low Pass Filter.
+//!
+//! `evaluate`/`magnitude_db` need the `num_complex` crate, and the
+//! `serde` feature gate below needs the `serde` crate with its `derive`
+//! feature; both must be declared in the crate manifest alongside this
+//! module.
 
 use super::*;
+use num_complex::Complex;
 
 /// High pass filter adapted from the 3rd Order Butterworth Low Pass Filter with
 /// resonance.
@@ -104,9 +110,37 @@ impl HighPass {
         self.b2 = ((-k*t - 3.0)/g) as SampleT;
         self.b3 = (1.0/g) as SampleT;
     }
+
+    /// Evaluates the filter's complex transfer function at `freq`, so
+    /// callers can plot magnitude/phase or verify the design. `.norm()` on
+    /// the result gives the linear gain, `.arg()` the phase in radians.
+    pub fn evaluate(&self, freq: MathT) -> Complex<MathT> {
+        let w = 2.0 * std::f64::consts::PI * freq * INV_SAMPLE_RATE;
+        let z_inv = Complex::new(0.0, -w).exp();
+
+        let num = self.a0 as MathT
+            + self.a1 as MathT * z_inv
+            + self.a2 as MathT * z_inv * z_inv
+            + self.a3 as MathT * z_inv * z_inv * z_inv;
+        let den = Complex::new(1.0, 0.0)
+            - self.b1 as MathT * z_inv
+            - self.b2 as MathT * z_inv * z_inv
+            - self.b3 as MathT * z_inv * z_inv * z_inv;
+
+        num / den
+    }
+
+    /// Convenience wrapper around [`HighPass::evaluate`] returning the gain
+    /// at `freq` in decibels, via the shared [`linear_db`] conversion.
+    ///
+    /// [`HighPass::evaluate`]: struct.HighPass.html#method.evaluate
+    /// [`linear_db`]: ../fn.linear_db.html
+    pub fn magnitude_db(&self, freq: MathT) -> MathT {
+        linear_db(self.evaluate(freq).norm())
+    }
 }
 
-impl Modifier for HighPass {
+impl Modifier<SampleT> for HighPass {
     fn process(&mut self, x: SampleT) -> SampleT {
         let y = self.a0*x + self.a1*self.x1 + self.a2*self.x2 + self.a3*self.x3 +
             self.b1*self.y1 + self.b2*self.y2 + self.b3*self.y3;
@@ -120,6 +154,34 @@ impl Modifier for HighPass {
 
         y
     }
+
+    fn process_block(&mut self, input: &[SampleT], output: &mut [SampleT]) {
+        let (a0, a1, a2, a3) = (self.a0, self.a1, self.a2, self.a3);
+        let (b1, b2, b3) = (self.b1, self.b2, self.b3);
+        let (mut x1, mut x2, mut x3) = (self.x1, self.x2, self.x3);
+        let (mut y1, mut y2, mut y3) = (self.y1, self.y2, self.y3);
+
+        for (x, y) in input.iter().zip(output.iter_mut()) {
+            let x = *x;
+            let out = a0*x + a1*x1 + a2*x2 + a3*x3 + b1*y1 + b2*y2 + b3*y3;
+
+            x3 = x2;
+            x2 = x1;
+            x1 = x;
+            y3 = y2;
+            y2 = y1;
+            y1 = out;
+
+            *y = out;
+        }
+
+        self.x1 = x1;
+        self.x2 = x2;
+        self.x3 = x3;
+        self.y1 = y1;
+        self.y2 = y2;
+        self.y3 = y3;
+    }
 }
 
 impl Clone for HighPass {
@@ -143,3 +205,34 @@ impl Clone for HighPass {
         }
     }
 }
+
+/// On-disk shape of a [`HighPass`] preset: just the user-facing parameters,
+/// not the transient delay-line state.
+///
+/// [`HighPass`]: struct.HighPass.html
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct HighPassParams {
+    fc: MathT,
+    r: MathT,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for HighPass {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        HighPassParams { fc: self.fc, r: self.r }.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HighPass {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: serde::Deserializer<'de>
+    {
+        let params = HighPassParams::deserialize(deserializer)?;
+
+        Ok(HighPass::new(params.fc, params.r))
+    }
+}
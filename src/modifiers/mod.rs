@@ -0,0 +1,48 @@
+//! # Modifiers
+//!
+//! Module including the types responsible for shaping audio samples produced
+//! by a [`Generator`], such as envelopes, filters, and dynamics processors.
+//!
+//! [`Generator`]: ../generators/trait.Generator.html
+
+use super::*;
+
+/// Trait implemented by types that transform an incoming sample in some way,
+/// such as a filter, envelope, or dynamics processor.
+pub trait Modifier<T = StereoData> {
+	/// Processes a single sample, returning the modified sample.
+	fn process(&mut self, x: T) -> T;
+
+	/// Processes a whole buffer at once, writing one output sample to
+	/// `output` for each input sample in `input`.
+	///
+	/// The default implementation just calls [`Modifier::process`] in a
+	/// loop. Implementors for which the per-sample call incurs real
+	/// overhead (coefficient loads, virtual dispatch) should override this
+	/// to copy their state into locals, run the recurrence over the slice,
+	/// then write the state back once.
+	///
+	/// [`Modifier::process`]: trait.Modifier.html#tymethod.process
+	fn process_block(&mut self, input: &[T], output: &mut [T])
+		where T: Copy
+	{
+		for (x, y) in input.iter().zip(output.iter_mut()) {
+			*y = self.process(*x);
+		}
+	}
+}
+
+/// Converts a linear amplitude to decibels; the inverse of `db_linear`.
+pub(crate) fn linear_db(linear: MathT) -> MathT {
+	20.0 * linear.max(1e-9).log10()
+}
+
+pub mod adsr;
+pub mod bandpass;
+pub mod biquad;
+pub mod butterworth;
+pub mod compressor;
+pub mod highpass;
+pub mod moog_low_pass;
+pub mod smoothed;
+pub mod zpk;
@@ -0,0 +1,97 @@
+//! # Moog Low Pass
+
+use super::*;
+use super::smoothed::{Smoothed, DEFAULT_RAMP};
+
+/// Four-pole resonant ladder low pass filter modeled after the classic Moog
+/// topology, giving users the characteristic resonant peak that a simple
+/// band-pass can't produce.
+pub struct MoogLowPass {
+	cutoff: Smoothed<MathT>,
+	resonance: Smoothed<MathT>,
+	g: MathT,
+	stage: [StereoData; 4],
+	feedback: StereoData,
+}
+
+impl MoogLowPass {
+	/// Constructs a new MoogLowPass.
+	///
+	/// # Parameters
+	///
+	/// * `cutoff` - The cutoff frequency in Hz.
+	/// * `resonance` - The resonance amount. Value is clamped to
+	/// `[0, 4)` to stay below the self-oscillation limit.
+	pub fn new(cutoff: MathT, resonance: MathT) -> Self {
+		let mut mlp = MoogLowPass {
+			cutoff: Smoothed::new(cutoff),
+			resonance: Smoothed::new(resonance.min(3.999).max(0.0)),
+			g: 0.0,
+			stage: [StereoData::default(); 4],
+			feedback: StereoData::default(),
+		};
+
+		mlp.reset();
+
+		mlp
+	}
+
+	/// Returns the cutoff frequency of the filter.
+	pub fn get_cutoff_frequency(&self) -> MathT {
+		self.cutoff.current()
+	}
+
+	/// Ramps the cutoff frequency toward `cutoff` over [`DEFAULT_RAMP`]
+	/// rather than snapping to it, so sweeping the cutoff live doesn't
+	/// zipper.
+	pub fn set_cutoff_frequency(&mut self, cutoff: MathT) {
+		self.cutoff.set_target(cutoff, DEFAULT_RAMP);
+	}
+
+	/// Returns the resonance of the filter.
+	pub fn get_resonance(&self) -> MathT {
+		self.resonance.current()
+	}
+
+	/// Ramps the resonance toward `resonance` over [`DEFAULT_RAMP`] rather
+	/// than snapping to it. Value is clamped to `[0, 4)` to stay below the
+	/// self-oscillation limit.
+	pub fn set_resonance(&mut self, resonance: MathT) {
+		self.resonance.set_target(resonance.min(3.999).max(0.0), DEFAULT_RAMP);
+	}
+
+	fn reset(&mut self) {
+		let fc = self.cutoff.current() * INV_SAMPLE_RATE;
+
+		self.g = 1.0 - (-2.0 * std::f64::consts::PI * fc).exp();
+	}
+
+	fn stage(input: StereoData, state: &mut StereoData, g: MathT) -> StereoData {
+		*state += (input - *state) * g;
+
+		*state
+	}
+}
+
+impl Modifier for MoogLowPass {
+	fn process(&mut self, x: StereoData) -> StereoData {
+		let cutoff_ramping = self.cutoff.is_ramping();
+		self.cutoff.tick();
+		self.resonance.tick();
+
+		if cutoff_ramping {
+			self.reset();
+		}
+
+		let input = x - self.feedback * self.resonance.current();
+
+		let mut y = Self::stage(input, &mut self.stage[0], self.g);
+		y = Self::stage(y, &mut self.stage[1], self.g);
+		y = Self::stage(y, &mut self.stage[2], self.g);
+		y = Self::stage(y, &mut self.stage[3], self.g);
+
+		self.feedback = y;
+
+		y
+	}
+}
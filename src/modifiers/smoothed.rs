@@ -0,0 +1,68 @@
+//! # Smoothed
+
+use super::*;
+use std::ops::{Add, Mul, Sub};
+use std::time::Duration;
+
+/// Default ramp time for an automated parameter change, shared by the
+/// filters that glide a parameter via [`Smoothed`] to avoid zipper noise.
+pub const DEFAULT_RAMP: Duration = Duration::from_millis(10);
+
+/// Reusable parameter ramp. Instead of jumping straight to a new target
+/// value, a `Smoothed<T>` steps toward it by a fixed per-sample increment
+/// over a configurable ramp time, so automating a filter or gain parameter
+/// during playback doesn't produce zipper noise.
+pub struct Smoothed<T> {
+	current: T,
+	target: T,
+	increment: T,
+	remaining: usize,
+}
+
+impl<T> Smoothed<T>
+where
+	T: Copy + PartialEq + Add<Output = T> + Sub<Output = T> + Mul<MathT, Output = T>,
+{
+	/// Creates a new Smoothed parameter already settled at `initial`.
+	pub fn new(initial: T) -> Self {
+		Smoothed {
+			current: initial,
+			target: initial,
+			increment: initial - initial,
+			remaining: 0,
+		}
+	}
+
+	/// Sets a new target value, to be reached after `ramp` has elapsed.
+	pub fn set_target(&mut self, target: T, ramp: Duration) {
+		let samples = (ramp.as_secs_f64() * SAMPLE_RATE as MathT).max(1.0);
+
+		self.target = target;
+		self.increment = (target - self.current) * (1.0 / samples);
+		self.remaining = samples as usize;
+	}
+
+	/// Advances the ramp by one sample, returning the new current value.
+	pub fn tick(&mut self) -> T {
+		if self.remaining > 0 {
+			self.current = self.current + self.increment;
+			self.remaining -= 1;
+
+			if self.remaining == 0 {
+				self.current = self.target;
+			}
+		}
+
+		self.current
+	}
+
+	/// Returns the current (possibly still ramping) value.
+	pub fn current(&self) -> T {
+		self.current
+	}
+
+	/// Returns `true` while the value is still ramping toward its target.
+	pub fn is_ramping(&self) -> bool {
+		self.remaining > 0
+	}
+}
@@ -0,0 +1,245 @@
+//! # ZPK Design
+//!
+//! A filter-design module that separates *design* from *runtime*: a filter
+//! is first described as a zero-pole-gain model in the analog s-domain,
+//! then mapped to a cascade of digital [`Biquad`]-style sections via the
+//! bilinear transform, rather than hand-deriving coefficient algebra for
+//! every new filter shape. New responses (elliptic, Chebyshev, ...) can be
+//! added later by supplying a different analog prototype rather than new
+//! recurrence code.
+//!
+//! Pole/zero arithmetic here needs the `num_complex` crate declared in the
+//! crate manifest alongside this module.
+//!
+//! [`Biquad`]: struct.Biquad.html
+
+use super::*;
+use num_complex::Complex;
+
+/// One conjugate pole pair (`p`, `conj(p)`) or, for the leftover pole of
+/// an odd order, a single real pole.
+enum Pole {
+	Pair(Complex<MathT>),
+	Real(MathT),
+}
+
+/// A filter described by its analog (s-domain) poles and zero, not yet
+/// mapped to a sample rate. Butterworth zeros are all coincident, so a
+/// single shared location is enough: `None` for the zero at infinity
+/// (low-pass), `Some` for a finite zero (high-pass, always at the
+/// origin).
+pub struct ZpkDesign {
+	poles: Vec<Pole>,
+	zero: Option<Complex<MathT>>,
+}
+
+impl ZpkDesign {
+	/// Places `order` poles evenly around the left half of the unit circle
+	/// (`s_k = e^{j*PI*(2k+N+1)/(2N)}`) to build the analog Butterworth
+	/// low-pass prototype, then frequency-scales it (`s -> s/wc`) for the
+	/// requested cutoff `fc`, prewarped for the bilinear transform.
+	pub fn butterworth_lowpass(order: usize, fc: MathT) -> Self {
+		let n = order.max(1);
+		let wc = prewarped_cutoff(fc);
+
+		let poles = prototype_poles(n)
+			.map(|p| scale_pole(p, wc))
+			.collect();
+
+		ZpkDesign { poles, zero: None }
+	}
+
+	/// Builds the analog Butterworth prototype as in
+	/// [`ZpkDesign::butterworth_lowpass`], then transforms it into a
+	/// high-pass (`s -> wc/s`) with a zero of multiplicity `order` at the
+	/// origin.
+	///
+	/// [`ZpkDesign::butterworth_lowpass`]: struct.ZpkDesign.html#method.butterworth_lowpass
+	pub fn butterworth_highpass(order: usize, fc: MathT) -> Self {
+		let n = order.max(1);
+		let wc = prewarped_cutoff(fc);
+
+		let poles = prototype_poles(n)
+			.map(|p| invert_pole(p, wc))
+			.collect();
+
+		ZpkDesign { poles, zero: Some(Complex::new(0.0, 0.0)) }
+	}
+
+	/// Maps the analog poles/zero to the digital domain with the prewarped
+	/// bilinear substitution and builds one section per stored pole (pair
+	/// or leftover real pole), returning the finished cascaded
+	/// [`Modifier`].
+	///
+	/// [`Modifier`]: trait.Modifier.html
+	pub fn build(&self) -> Cascade {
+		let t = INV_SAMPLE_RATE;
+
+		// A zero at infinity has no finite image under the bilinear
+		// transform; it maps to z=-1 by convention (matching the
+		// low-pass case's DC-normalized zero at Nyquist).
+		let digital_zero = match self.zero {
+			Some(z) => bilinear(z, t),
+			None => Complex::new(-1.0, 0.0),
+		};
+
+		let stages = self.poles.iter()
+			.map(|pole| match pole {
+				Pole::Pair(p) => Section::from_pair(bilinear(*p, t), digital_zero),
+				Pole::Real(p) => Section::from_real(bilinear(Complex::new(*p, 0.0), t).re, digital_zero.re),
+			})
+			.collect();
+
+		Cascade { stages }
+	}
+}
+
+/// Prewarps `fc` for the bilinear transform: `wc = (2/T)*tan(PI*fc*T)`.
+fn prewarped_cutoff(fc: MathT) -> MathT {
+	let t = INV_SAMPLE_RATE;
+
+	(2.0 / t) * (std::f64::consts::PI * fc * t).tan()
+}
+
+/// The analog Butterworth low-pass prototype's poles, one entry per
+/// conjugate pair (plus a trailing real pole for odd `n`), each on the
+/// left half of the unit circle at `s_k = e^{j*PI*(2k+N+1)/(2N)}`.
+fn prototype_poles(n: usize) -> impl Iterator<Item = Pole> {
+	(0..(n + 1) / 2).map(move |k| {
+		let theta = std::f64::consts::PI * (2 * k + n + 1) as MathT / (2.0 * n as MathT);
+		let p = Complex::new(theta.cos(), theta.sin());
+
+		if n % 2 == 1 && k == n / 2 {
+			Pole::Real(p.re)
+		} else {
+			Pole::Pair(p)
+		}
+	})
+}
+
+/// Frequency-scales a low-pass prototype pole (`s -> s/wc`).
+fn scale_pole(pole: Pole, wc: MathT) -> Pole {
+	match pole {
+		Pole::Pair(p) => Pole::Pair(p * wc),
+		Pole::Real(p) => Pole::Real(p * wc),
+	}
+}
+
+/// Transforms a low-pass prototype pole into a high-pass pole
+/// (`s -> wc/s`).
+fn invert_pole(pole: Pole, wc: MathT) -> Pole {
+	match pole {
+		Pole::Pair(p) => Pole::Pair(Complex::new(wc, 0.0) / p),
+		Pole::Real(p) => Pole::Real(wc / p),
+	}
+}
+
+/// Maps an analog pole/zero `s` to the digital domain via the prewarped
+/// bilinear substitution `s = (2/T)*(1 - z^-1)/(1 + z^-1)`, solved for `z`.
+fn bilinear(s: Complex<MathT>, t: MathT) -> Complex<MathT> {
+	let two_over_t = Complex::new(2.0 / t, 0.0);
+
+	(two_over_t + s) / (two_over_t - s)
+}
+
+/// A single second- (or, for a leftover pole, first-) order digital
+/// section produced by [`ZpkDesign::build`].
+///
+/// [`ZpkDesign::build`]: struct.ZpkDesign.html#method.build
+struct Section {
+	b0: SampleT,
+	b1: SampleT,
+	b2: SampleT,
+	a1: SampleT,
+	a2: SampleT,
+	x1: SampleT,
+	x2: SampleT,
+	y1: SampleT,
+	y2: SampleT,
+}
+
+impl Section {
+	/// Builds a second-order section from a digital conjugate pole pair
+	/// (`p`, `conj(p)`) and a matching zero pair at `digital_zero` (both
+	/// real, for Butterworth designs), normalized to unity gain at DC
+	/// (low-pass, `eval_at = 1`) or Nyquist (high-pass, `eval_at = -1`).
+	fn from_pair(p: Complex<MathT>, digital_zero: Complex<MathT>) -> Self {
+		let a1 = -2.0 * p.re;
+		let a2 = p.norm_sqr();
+
+		let zero_b1 = -2.0 * digital_zero.re;
+		let eval_at = if digital_zero.re > 0.0 { -1.0 } else { 1.0 };
+		let num_unnorm = 1.0 + zero_b1 * eval_at + eval_at * eval_at;
+		let den = 1.0 + a1 * eval_at + a2 * eval_at * eval_at;
+		let gain = den / num_unnorm;
+
+		Section {
+			b0: gain as SampleT,
+			b1: (gain * zero_b1) as SampleT,
+			b2: gain as SampleT,
+			a1: a1 as SampleT,
+			a2: a2 as SampleT,
+			x1: SampleT::default(),
+			x2: SampleT::default(),
+			y1: SampleT::default(),
+			y2: SampleT::default(),
+		}
+	}
+
+	/// Builds a first-order section from a single real digital pole `p`
+	/// and a single zero at `digital_zero`, used for the leftover pole of
+	/// an odd-order cascade.
+	fn from_real(p: MathT, digital_zero: MathT) -> Self {
+		let a1 = -p;
+
+		let zero_b1 = -digital_zero;
+		let eval_at = if digital_zero > 0.0 { -1.0 } else { 1.0 };
+		let num_unnorm = 1.0 + zero_b1 * eval_at;
+		let den = 1.0 + a1 * eval_at;
+		let gain = den / num_unnorm;
+
+		Section {
+			b0: gain as SampleT,
+			b1: (gain * zero_b1) as SampleT,
+			b2: 0.0,
+			a1: a1 as SampleT,
+			a2: 0.0,
+			x1: SampleT::default(),
+			x2: SampleT::default(),
+			y1: SampleT::default(),
+			y2: SampleT::default(),
+		}
+	}
+
+	fn process(&mut self, x: SampleT) -> SampleT {
+		let y = self.b0 * x + self.b1 * self.x1 + self.b2 * self.x2
+			- self.a1 * self.y1 - self.a2 * self.y2;
+
+		self.x2 = self.x1;
+		self.x1 = x;
+		self.y2 = self.y1;
+		self.y1 = y;
+
+		y
+	}
+}
+
+/// The cascaded digital [`Modifier`] returned by [`ZpkDesign::build`].
+///
+/// [`Modifier`]: trait.Modifier.html
+/// [`ZpkDesign::build`]: struct.ZpkDesign.html#method.build
+pub struct Cascade {
+	stages: Vec<Section>,
+}
+
+impl Modifier<SampleT> for Cascade {
+	fn process(&mut self, x: SampleT) -> SampleT {
+		let mut y = x;
+
+		for stage in &mut self.stages {
+			y = stage.process(y);
+		}
+
+		y
+	}
+}
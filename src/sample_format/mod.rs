@@ -0,0 +1,12 @@
+//! # Sample Format
+//!
+//! Module containing types for handling and converting raw audio sample
+//! data, including the crate's [`StereoData`] representation and utilities
+//! for moving it between sample rates.
+//!
+//! [`StereoData`]: stereodata/struct.StereoData.html
+
+use super::*;
+
+pub mod resampler;
+pub mod stereodata;
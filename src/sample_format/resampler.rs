@@ -0,0 +1,69 @@
+//! # Resampler
+
+use super::*;
+
+/// Push-based sample-rate converter for [`StereoData`] streams.
+///
+/// Audio is fed in at one rate via [`Resampler::feed`] and produces zero or
+/// more samples at the target rate, interpolated between the two most
+/// recently received input samples with cosine interpolation. This is
+/// cheap compared to a windowed-sinc resampler while avoiding the harsh
+/// aliasing of nearest-neighbor, which is the right trade-off for bridging
+/// a loaded file or a piece of hardware running at a different rate than
+/// [`SAMPLE_RATE`].
+///
+/// [`StereoData`]: struct.StereoData.html
+/// [`Resampler::feed`]: struct.Resampler.html#method.feed
+/// [`SAMPLE_RATE`]: ../core/constant.SAMPLE_RATE.html
+pub struct Resampler {
+	step: MathT,
+	phase: MathT,
+	last: StereoData,
+	current: StereoData,
+	primed: bool,
+}
+
+impl Resampler {
+	/// Constructs a new Resampler converting from `in_rate` to `out_rate`,
+	/// both in Hz.
+	pub fn new(in_rate: MathT, out_rate: MathT) -> Self {
+		Resampler {
+			step: in_rate / out_rate,
+			phase: 0.0,
+			last: StereoData::default(),
+			current: StereoData::default(),
+			primed: false,
+		}
+	}
+
+	/// Feeds a single input sample to the resampler, returning the (possibly
+	/// empty) run of output samples it produces at the target rate.
+	pub fn feed(&mut self, sample: StereoData) -> Vec<StereoData> {
+		let mut out = Vec::new();
+
+		if !self.primed {
+			self.current = sample;
+			self.primed = true;
+
+			return out;
+		}
+
+		self.last = self.current;
+		self.current = sample;
+
+		while self.phase < 1.0 {
+			let mu = (1.0 - (std::f64::consts::PI * self.phase).cos()) / 2.0;
+
+			out.push(StereoData::from_stereo(
+				(self.current.left() as MathT * mu + self.last.left() as MathT * (1.0 - mu)) as SampleT,
+				(self.current.right() as MathT * mu + self.last.right() as MathT * (1.0 - mu)) as SampleT,
+			));
+
+			self.phase += self.step;
+		}
+
+		self.phase -= 1.0;
+
+		out
+	}
+}
@@ -0,0 +1,27 @@
+//! # Basic Block
+
+use super::*;
+use std::rc::Rc;
+
+/// Trait implemented by anything that can sit as a node in a
+/// [`ComplexSound`]'s graph: it accumulates primed input samples from its
+/// upstream neighbors, then produces an output sample when processed.
+///
+/// [`ComplexSound`]: complex_sound/struct.ComplexSound.html
+pub trait BasicBlock {
+	/// Accumulates `x` into the block's pending input. Called once per
+	/// incoming edge before [`BasicBlock::process`] is called.
+	///
+	/// [`BasicBlock::process`]: trait.BasicBlock.html#tymethod.process
+	fn prime_input(&mut self, x: StereoData);
+
+	/// Produces this block's output for the current sample, consuming and
+	/// resetting whatever input was primed.
+	fn process(&mut self) -> StereoData;
+}
+
+/// Reference-counted handle to a block insertable into a [`ComplexSound`]'s
+/// graph.
+///
+/// [`ComplexSound`]: complex_sound/struct.ComplexSound.html
+pub type BlockRc = Rc<dyn BasicBlock>;
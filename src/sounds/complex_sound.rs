@@ -12,6 +12,7 @@ use std::rc::Rc;
 use std::collections::VecDeque;
 use crate::core::*;
 use super::basic_block::*;
+use super::scope_block::{ScopeBlock, ScopeHandle};
 use petgraph::graph;
 
 /// Graph type for [`ComplexSound`] 
@@ -58,6 +59,22 @@ impl ComplexSound {
 		self.process_order();
 	}
 
+	/// Taps the output of `from` with a [`ScopeBlock`], so the signal
+	/// flowing out of that node can be observed without affecting the
+	/// audio result. Returns the [`ScopeHandle`] used to read back the
+	/// captured samples.
+	///
+	/// [`ScopeBlock`]: scope_block/struct.ScopeBlock.html
+	/// [`ScopeHandle`]: scope_block/type.ScopeHandle.html
+	pub fn add_scope(&mut self, from: GraphNode, capacity: usize) -> ScopeHandle {
+		let (scope, handle) = ScopeBlock::new(capacity);
+		let node = self.graph.add_node(Rc::new(scope));
+
+		self.add_connection(from, node);
+
+		handle
+	}
+
 	pub fn process(&mut self, input: StereoData) -> StereoData {
 		if self.is_paused {
 			return Default::default();
@@ -0,0 +1,96 @@
+//! # Complex Sound Chain
+//!
+//! A fluent builder layered on top of [`ComplexSound`]'s raw [`Graph`], so
+//! common linear and branch/merge topologies can be assembled without
+//! juggling [`GraphNode`] indices by hand.
+//!
+//! [`ComplexSound`]: struct.ComplexSound.html
+//! [`Graph`]: type.Graph.html
+//! [`GraphNode`]: type.GraphNode.html
+
+use super::*;
+
+/// Builder that wires a sequence of [`BlockRc`]s into a [`ComplexSound`],
+/// auto-connecting each pushed block to whatever the chain currently ends
+/// at.
+///
+/// [`BlockRc`]: type.BlockRc.html
+/// [`ComplexSound`]: struct.ComplexSound.html
+pub struct ComplexSoundChain {
+	sound: ComplexSound,
+	tail: GraphNode,
+}
+
+impl ComplexSoundChain {
+	/// Starts a new chain. The chain begins at the [`ComplexSound`]'s input
+	/// gain node, so the first [`Self::then`] connects directly to it.
+	///
+	/// [`ComplexSound`]: struct.ComplexSound.html
+	/// [`Self::then`]: struct.ComplexSoundChain.html#method.then
+	pub fn new(input_gain: SampleT, output_gain: SampleT) -> Self {
+		let sound = ComplexSound::new(input_gain, output_gain);
+		let tail = sound.get_input_gain();
+
+		ComplexSoundChain { sound, tail }
+	}
+
+	/// Adds `block` to the graph and connects it to the current end of the
+	/// chain, making it the new end.
+	pub fn then(mut self, block: BlockRc) -> Self {
+		let node = self.sound.add_block(block);
+		self.sound.add_connection(self.tail, node);
+		self.tail = node;
+
+		self
+	}
+
+	/// Fans the current end of the chain out into `blocks`, returning the
+	/// chain (still ending at the node it started this call at) alongside
+	/// the [`GraphNode`] of each new branch so they can later be passed to
+	/// [`Self::merge`].
+	///
+	/// [`GraphNode`]: type.GraphNode.html
+	/// [`Self::merge`]: struct.ComplexSoundChain.html#method.merge
+	pub fn branch(mut self, blocks: Vec<BlockRc>) -> (Self, Vec<GraphNode>) {
+		let from = self.tail;
+
+		let nodes = blocks.into_iter().map(|b| {
+			let node = self.sound.add_block(b);
+			self.sound.add_connection(from, node);
+			node
+		}).collect();
+
+		(self, nodes)
+	}
+
+	/// Sums `branches` back into a single block, making that block the new
+	/// end of the chain. Because [`BasicBlock::prime_input`] accumulates
+	/// rather than overwrites, connecting several nodes to the same block
+	/// is already a sum of their outputs — no separate mixing block is
+	/// required.
+	///
+	/// [`BasicBlock::prime_input`]: trait.BasicBlock.html#tymethod.prime_input
+	pub fn merge(mut self, branches: &[GraphNode], into: BlockRc) -> Self {
+		let node = self.sound.add_block(into);
+
+		for &b in branches {
+			self.sound.add_connection(b, node);
+		}
+
+		self.tail = node;
+
+		self
+	}
+
+	/// Connects the current end of the chain to the output gain and returns
+	/// the finished [`ComplexSound`], with its process order already
+	/// recomputed by the connections made along the way.
+	///
+	/// [`ComplexSound`]: struct.ComplexSound.html
+	pub fn finish(mut self) -> ComplexSound {
+		let output_gain = self.sound.get_output_gain();
+		self.sound.add_connection(self.tail, output_gain);
+
+		self.sound
+	}
+}
@@ -0,0 +1,18 @@
+//! # Sounds
+//!
+//! Module containing the types used to assemble [`Generator`]s and
+//! [`Modifier`]s into playable sounds, from a single [`Block`] up to whole
+//! [`ComplexSound`] graphs.
+//!
+//! [`Generator`]: ../generators/trait.Generator.html
+//! [`Modifier`]: ../modifiers/trait.Modifier.html
+//! [`Block`]: block/struct.Block.html
+//! [`ComplexSound`]: complex_sound/struct.ComplexSound.html
+
+use super::*;
+
+pub mod basic_block;
+pub mod block;
+pub mod complex_sound;
+pub mod complex_sound_chain;
+pub mod scope_block;
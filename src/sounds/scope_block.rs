@@ -0,0 +1,65 @@
+//! # Scope Block
+
+use super::*;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+/// Shared handle to a [`ScopeBlock`]'s capture buffer. Cloning it gives
+/// another owner (a meter, an oscilloscope, a test assertion) read access
+/// to the same ring buffer the block is writing into.
+///
+/// [`ScopeBlock`]: struct.ScopeBlock.html
+pub type ScopeHandle = Rc<RefCell<VecDeque<StereoData>>>;
+
+/// A transparent tap that passes its primed input straight through
+/// unchanged while also recording every sample it sees into a bounded ring
+/// buffer, so a [`ComplexSound`] graph can be observed from the outside
+/// without altering its audio result.
+///
+/// [`ComplexSound`]: complex_sound/struct.ComplexSound.html
+pub struct ScopeBlock {
+	input: StereoData,
+	capacity: usize,
+	capture: ScopeHandle,
+}
+
+impl ScopeBlock {
+	/// Creates a new ScopeBlock capturing up to `capacity` samples, along
+	/// with the [`ScopeHandle`] used to read them back.
+	///
+	/// [`ScopeHandle`]: type.ScopeHandle.html
+	pub fn new(capacity: usize) -> (Self, ScopeHandle) {
+		let capture: ScopeHandle = Rc::new(RefCell::new(VecDeque::with_capacity(capacity)));
+
+		(
+			ScopeBlock {
+				input: StereoData::default(),
+				capacity,
+				capture: capture.clone(),
+			},
+			capture,
+		)
+	}
+}
+
+impl BasicBlock for ScopeBlock {
+	fn prime_input(&mut self, x: StereoData) {
+		self.input += x;
+	}
+
+	fn process(&mut self) -> StereoData {
+		let y = self.input;
+
+		let mut capture = self.capture.borrow_mut();
+		if capture.len() >= self.capacity {
+			capture.pop_front();
+		}
+		capture.push_back(y);
+		drop(capture);
+
+		self.input = StereoData::default();
+
+		y
+	}
+}